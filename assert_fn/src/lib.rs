@@ -2,7 +2,7 @@
 
 use proc_macro::TokenStream;
 use syn::{
-    parse_macro_input, AttributeArgs, GenericArgument, ItemFn, Lit, Meta, NestedMeta,
+    parse_macro_input, AttributeArgs, FnArg, GenericArgument, ItemFn, Lit, Meta, NestedMeta, Pat,
     PathArguments, PathSegment, ReturnType, Type,
 };
 
@@ -20,6 +20,20 @@ use syn::{
 /// assert!(catch_unwind(|| assert_is_ten!(9)).is_err());
 /// ```
 ///
+/// If no custom message is given, the failure panics with every argument's name and value so
+/// you don't have to guess what was passed in.
+/// ```
+/// # use assert_fn::assert_fn;
+/// # use test_helpers::{catch_panic_message, PanicMessage};
+/// #[assert_fn]
+/// fn is_ten(num: usize) -> bool {
+///     num == 10
+/// }
+///
+/// let result = catch_panic_message(|| assert_is_ten!(9));
+/// assert_eq!(result, PanicMessage::Message("... (num = 9)".to_string()));
+/// ```
+///
 /// A custom message can be specified on the `#[assert_fn]` macro, e.g.
 /// ```
 /// # use assert_fn::assert_fn;
@@ -101,6 +115,23 @@ use syn::{
 /// }
 /// ```
 ///
+/// If an `Err` should itself be treated as an assertion failure rather than being passed through,
+/// opt in with `#[assert_fn(require_ok)]`. The generated macro then panics with the error's
+/// `Debug` output instead of returning it.
+/// ```
+/// # use assert_fn::assert_fn;
+/// # use std::panic::catch_unwind;
+/// #[assert_fn(require_ok)]
+/// fn is_ten(num: usize) -> Result<bool, String> {
+///     if num > 100 {
+///         return Err("number too large".to_string());
+///     }
+///     Ok(num == 10)
+/// }
+///
+/// assert!(catch_unwind(|| assert_is_ten!(200)).is_err());
+/// ```
+///
 /// Finally, as demonstrated in the Result example, the return value of your assert function is returned
 /// from the macro. This allows you to get back additional useful values from your assert to use elsewhere
 /// in your test.
@@ -114,37 +145,304 @@ use syn::{
 /// let (_, _, value) = assert_is_ten!(10);
 /// assert_eq!(&value, "Some other useful value")
 /// ```
+///
+/// `case(...)` arguments generate a table-driven `#[test]` per case, saving you from hand-wiring
+/// one. Append `panics` to a case to expect a panic, or `message = "..."` to expect a specific
+/// panic message.
+/// ```
+/// # use assert_fn::assert_fn;
+/// #[assert_fn(case(10), case(9, panics), case(8, message = "... (num = 8)"))]
+/// fn is_ten(num: usize) -> bool {
+///     num == 10
+/// }
+/// ```
+///
+/// `#[assert_fn(negate)]` additionally generates an `assert_not_<name>!` companion that asserts
+/// the opposite, and every non-`async` `#[assert_fn]` also generates an `assert_<name>_panics!` companion
+/// (built on `test_helpers::catch_panic_message`) that asserts the function panics, optionally
+/// matching the panic message against a substring.
+/// ```
+/// # use assert_fn::assert_fn;
+/// #[assert_fn(negate)]
+/// fn is_ten(num: usize) -> bool {
+///     num == 10
+/// }
+///
+/// assert_not_is_ten!(9);
+/// ```
+///
+/// `#[assert_fn(payload)]` swaps the formatted panic message for a structured
+/// `test_helpers::AssertFailure` payload (via `std::panic::panic_any`), so a test can downcast
+/// and inspect the failure instead of pattern-matching a rendered string. Requires a
+/// tuple-returning function.
+/// ```
+/// # use assert_fn::assert_fn;
+/// # use test_helpers::{catch_panic_message, PanicMessage};
+/// #[assert_fn(payload)]
+/// fn is_ten(num: usize) -> (usize, usize) {
+///     (num, 10)
+/// }
+///
+/// let result = catch_panic_message(|| assert_is_ten!(9));
+/// match result {
+///     PanicMessage::Payload(failure) => assert_eq!(failure.left, "9"),
+///     _ => panic!("expected a payload"),
+/// }
+/// ```
+///
+/// `payload` composes with `message(...)` (and the default "arg = value" diagnostic when no
+/// message is given) - the rendered text lands in `AssertFailure.message` instead of being
+/// dropped.
+/// ```
+/// # use assert_fn::assert_fn;
+/// # use test_helpers::{catch_panic_message, PanicMessage};
+/// #[assert_fn(payload, message("That wasn't ten"))]
+/// fn is_ten(num: usize) -> (usize, usize) {
+///     (num, 10)
+/// }
+///
+/// let result = catch_panic_message(|| assert_is_ten!(9));
+/// match result {
+///     PanicMessage::Payload(failure) => {
+///         assert_eq!(failure.message, Some("That wasn't ten".to_string()))
+///     }
+///     _ => panic!("expected a payload"),
+/// }
+/// ```
+///
+/// `#[assert_fn(no_std)]` (or `no_std(capacity)` for a non-default buffer size) renders the
+/// failure message into a fixed-capacity `arrayvec::ArrayString` via `core::fmt::Write` instead
+/// of allocating a `String`, so the generated assertion can run in a `#![no_std]` test harness.
+/// `#[assert_fn(no_std(64))]` uses a 64 byte buffer instead of the default 256. That `arrayvec`
+/// path only compiles in when *your* crate enables its own `no_std` Cargo feature and depends on
+/// `arrayvec` - with the feature off, which is the default, the assertion below falls back to the
+/// ordinary `std` formatting unchanged, so `no_std(...)` is safe to leave on an assertion even in
+/// a crate that never turns the feature on.
+/// ```
+/// # use assert_fn::assert_fn;
+/// # use std::panic::catch_unwind;
+/// #[assert_fn(no_std(64))]
+/// fn is_ten(num: usize) -> bool {
+///     num == 10
+/// }
+///
+/// assert_is_ten!(10);
+/// assert!(catch_unwind(|| assert_is_ten!(9)).is_err());
+/// ```
+///
+/// `#[assert_fn(context)]` appends every tuple element beyond `left`/`right` to the failure
+/// message as a labeled `Debug` line, so you get the "other useful values" a function computed
+/// without listing them all in `message(...)`.
+/// ```
+/// # use assert_fn::assert_fn;
+/// # use test_helpers::{catch_panic_message, PanicMessage};
+/// #[assert_fn(context)]
+/// fn is_ten(num: usize) -> (usize, usize, String) {
+///     (num, 10, "Some other useful value".to_string())
+/// }
+///
+/// let result = catch_panic_message(|| assert_is_ten!(9));
+/// assert_eq!(
+///     result,
+///     PanicMessage::Message(
+///         "assertion failed: `(left == right)`\n  left: `9`,\n right: `10`: ... (num = 9)\n    result.2 = \"Some other useful value\""
+///             .to_string()
+///     )
+/// );
+/// ```
 pub fn assert_fn(args: TokenStream, item: TokenStream) -> TokenStream {
+    create_assert_macro(AssertType::Eq, args, item)
+}
+
+#[proc_macro_attribute]
+/// Like [`assert_fn`], but asserts the tuple halves are *not* equal (`assert_ne!`). On a
+/// `bool`-returning function it asserts the result is `false`.
+/// ```
+/// # use assert_fn::assert_ne_fn;
+/// # use std::panic::catch_unwind;
+/// #[assert_ne_fn]
+/// fn is_ten(num: usize) -> (usize, usize) {
+///     (num, 10)
+/// }
+///
+/// assert_is_ten!(9);
+/// assert!(catch_unwind(|| assert_is_ten!(10)).is_err());
+/// ```
+pub fn assert_ne_fn(args: TokenStream, item: TokenStream) -> TokenStream {
+    create_assert_macro(AssertType::Ne, args, item)
+}
+
+#[proc_macro_attribute]
+/// Like [`assert_fn`], but asserts `result.0 < result.1`. Requires a tuple-returning function.
+/// ```
+/// # use assert_fn::assert_lt_fn;
+/// # use std::panic::catch_unwind;
+/// #[assert_lt_fn]
+/// fn is_under_ten(num: usize) -> (usize, usize) {
+///     (num, 10)
+/// }
+///
+/// assert_is_under_ten!(9);
+/// assert!(catch_unwind(|| assert_is_under_ten!(10)).is_err());
+/// ```
+pub fn assert_lt_fn(args: TokenStream, item: TokenStream) -> TokenStream {
+    create_assert_macro(AssertType::Lt, args, item)
+}
+
+#[proc_macro_attribute]
+/// Like [`assert_fn`], but asserts `result.0 <= result.1`. Requires a tuple-returning function.
+/// ```
+/// # use assert_fn::assert_le_fn;
+/// # use std::panic::catch_unwind;
+/// #[assert_le_fn]
+/// fn is_at_most_ten(num: usize) -> (usize, usize) {
+///     (num, 10)
+/// }
+///
+/// assert_is_at_most_ten!(10);
+/// assert!(catch_unwind(|| assert_is_at_most_ten!(11)).is_err());
+/// ```
+pub fn assert_le_fn(args: TokenStream, item: TokenStream) -> TokenStream {
+    create_assert_macro(AssertType::Le, args, item)
+}
+
+#[proc_macro_attribute]
+/// Like [`assert_fn`], but asserts `result.0 > result.1`. Requires a tuple-returning function.
+/// ```
+/// # use assert_fn::assert_gt_fn;
+/// # use std::panic::catch_unwind;
+/// #[assert_gt_fn]
+/// fn is_over_ten(num: usize) -> (usize, usize) {
+///     (num, 10)
+/// }
+///
+/// assert_is_over_ten!(11);
+/// assert!(catch_unwind(|| assert_is_over_ten!(10)).is_err());
+/// ```
+pub fn assert_gt_fn(args: TokenStream, item: TokenStream) -> TokenStream {
+    create_assert_macro(AssertType::Gt, args, item)
+}
+
+#[proc_macro_attribute]
+/// Like [`assert_fn`], but asserts `result.0 >= result.1`. Requires a tuple-returning function.
+/// ```
+/// # use assert_fn::assert_ge_fn;
+/// # use std::panic::catch_unwind;
+/// #[assert_ge_fn]
+/// fn is_at_least_ten(num: usize) -> (usize, usize) {
+///     (num, 10)
+/// }
+///
+/// assert_is_at_least_ten!(10);
+/// assert!(catch_unwind(|| assert_is_at_least_ten!(9)).is_err());
+/// ```
+pub fn assert_ge_fn(args: TokenStream, item: TokenStream) -> TokenStream {
+    create_assert_macro(AssertType::Ge, args, item)
+}
+
+enum AssertType {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// The true logical complement of an `AssertType`, used to build the `#[assert_fn(negate)]`
+/// companion: `Eq`'s opposite is `Ne` (and vice versa), while an ordering's opposite is the
+/// *other* ordering that covers every case it doesn't (`Lt` negates to `Ge`, not `Ne`, since
+/// `!(a < b)` is `a >= b`).
+fn negate_assert_type(assert_type: &AssertType) -> AssertType {
+    match assert_type {
+        AssertType::Eq => AssertType::Ne,
+        AssertType::Ne => AssertType::Eq,
+        AssertType::Lt => AssertType::Ge,
+        AssertType::Le => AssertType::Gt,
+        AssertType::Gt => AssertType::Le,
+        AssertType::Ge => AssertType::Lt,
+    }
+}
+
+fn create_assert_macro(
+    assert_type: AssertType,
+    args: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
     let raw_item = item.clone();
     let item = parse_macro_input!(item as ItemFn);
     let args = parse_macro_input!(args as AttributeArgs);
 
     let return_type = get_return_type(&item);
-    let assert_message = get_message(&args);
+    let assert_message = match get_message(&args) {
+        Ok(message) => message,
+        Err(compile_error) => return compile_error.into(),
+    };
 
     let fn_name = item.sig.ident.to_string();
-    let (params, values) = get_values_and_params(&item);
+    let (params, values, param_idents) = get_values_and_params(&item);
     let (async_block, dot_await) = get_async(&item);
     let tuple_destructure = get_tuple_destructure(&assert_message, &return_type);
-    let (if_result_open, if_result_close) = get_result_block(&return_type);
-    let assert_call = get_assert_call(&return_type);
-    let message = assert_message.map(|msg| msg.message).unwrap_or_default();
+    let require_ok = has_flag(&args, "require_ok");
+    let (if_result_open, if_result_close) = get_result_block(&return_type, require_ok);
+    let assert_call = get_assert_call(&return_type, &assert_type);
+    let arg_bindings = get_arg_bindings(&param_idents);
+    let assert_stmt = build_assert_stmt(
+        &args,
+        &assert_type,
+        &return_type,
+        &assert_message,
+        &param_idents,
+    );
+    let test_cases = get_test_cases(&args, &fn_name, item.sig.asyncness.is_some());
+    let negate_macro = if has_flag(&args, "negate") {
+        let negate_assert_type = negate_assert_type(&assert_type);
+        let negate_assert_call = get_assert_call(&return_type, &negate_assert_type);
+        let negate_assert_stmt = build_assert_stmt(
+            &args,
+            &negate_assert_type,
+            &return_type,
+            &assert_message,
+            &param_idents,
+        );
+        get_negate_macro(
+            &fn_name,
+            &params,
+            &values,
+            &async_block,
+            &dot_await,
+            &if_result_open,
+            &if_result_close,
+            &arg_bindings,
+            &negate_assert_call,
+            &negate_assert_stmt,
+        )
+    } else {
+        "".to_string()
+    };
+    let panics_macro = if item.sig.asyncness.is_none() {
+        get_panics_macro(&fn_name, &params, &values, &arg_bindings)
+    } else {
+        "".to_string()
+    };
 
     format!(
         r#"
         #[macro_export]
         macro_rules! assert_{fn_name} {{
             ({params_trimmed}$(,)?) => {{ {async_block} {{
+                {arg_bindings}
                 let result = {fn_name}({values}){dot_await};
 
                 {if_result_open}
                 {tuple_destructure}
-                {assert_call}{message});
+                {assert_stmt}
                 {if_result_close}
 
                 result
             }}}};
             ({params}$($arg:tt)+) => {{ {async_block} {{
+                {arg_bindings}
                 let result = {fn_name}({values}){dot_await};
 
                 {if_result_open}
@@ -155,7 +453,13 @@ pub fn assert_fn(args: TokenStream, item: TokenStream) -> TokenStream {
             }}}};
         }}
 
+        {negate_macro}
+
+        {panics_macro}
+
         {original_fn}
+
+        {test_cases}
     "#,
         fn_name = fn_name,
         params = params,
@@ -167,8 +471,12 @@ pub fn assert_fn(args: TokenStream, item: TokenStream) -> TokenStream {
         if_result_open = if_result_open,
         if_result_close = if_result_close,
         assert_call = assert_call,
-        message = message,
-        original_fn = raw_item.to_string()
+        assert_stmt = assert_stmt,
+        arg_bindings = arg_bindings,
+        negate_macro = negate_macro,
+        panics_macro = panics_macro,
+        original_fn = raw_item.to_string(),
+        test_cases = test_cases
     )
     .parse()
     .expect("Generated invalid tokens")
@@ -252,18 +560,84 @@ fn get_return_result_type(fn_name: &str, path_segment: &PathSegment) -> AssertRe
     }
 }
 
-fn get_values_and_params(item: &ItemFn) -> (String, String) {
+fn get_values_and_params(item: &ItemFn) -> (String, String, Vec<Option<String>>) {
     item.sig.inputs.iter().enumerate().fold(
-        ("".to_string(), "".to_string()),
-        |(params, values), (n, _)| {
+        ("".to_string(), "".to_string(), Vec::new()),
+        |(params, values, mut param_idents), (n, input)| {
+            param_idents.push(get_param_ident(input));
             (
                 format!("{}$arg_{}:expr,", params, n),
-                format!("{}$arg_{},", values, n),
+                format!("{}__arg_{},", values, n),
+                param_idents,
             )
         },
     )
 }
 
+/// Extracts the plain identifier a function argument is bound to, e.g. `num` for `num: usize`.
+/// Arguments bound via a more complex pattern (destructuring, `_`, `self`) have no single value
+/// worth reporting, so they're skipped rather than forced into the diagnostic message.
+fn get_param_ident(input: &FnArg) -> Option<String> {
+    match input {
+        FnArg::Typed(pat_type) => match &*pat_type.pat {
+            Pat::Ident(pat_ident) => Some(pat_ident.ident.to_string()),
+            _ => None,
+        },
+        FnArg::Receiver(_) => None,
+    }
+}
+
+/// Binds each macro argument to a `__arg_N` local before the function call, so its value is
+/// captured once and can be reused both to call the function and to render it in a failure
+/// message, mirroring how `std`'s `assert!` captures sub-expressions.
+fn get_arg_bindings(param_idents: &[Option<String>]) -> String {
+    (0..param_idents.len())
+        .map(|n| format!("let __arg_{n} = $arg_{n};", n = n))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The default `(format string, format args)` pair used to build the `", \"... (name = {:?},
+/// ...)\", ..."` suffix appended to the generated assert when the caller hasn't supplied their
+/// own `message(...)`. Returned raw (rather than already joined into a suffix) so
+/// `#[assert_fn(no_std(...))]` can render them into a fixed-capacity buffer instead.
+fn get_default_message_parts(param_idents: &[Option<String>]) -> (String, String) {
+    let labeled: Vec<(usize, &str)> = param_idents
+        .iter()
+        .enumerate()
+        .filter_map(|(n, ident)| ident.as_deref().map(|ident| (n, ident)))
+        .collect();
+
+    if labeled.is_empty() {
+        return ("".to_string(), "".to_string());
+    }
+
+    let format_str = labeled
+        .iter()
+        .map(|(_, ident)| format!("{} = {{:?}}", ident))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let format_args = labeled
+        .iter()
+        .map(|(n, _)| format!("__arg_{}", n))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    (format!("... ({})", format_str), format_args)
+}
+
+/// Joins a format string and its already-rendered args into the `, "fmt", args` suffix appended
+/// directly after an `assert!`/`assert_eq!` call's condition.
+fn render_message_suffix(fmt_text: &str, format_args: &str) -> String {
+    if fmt_text.is_empty() {
+        "".to_string()
+    } else if format_args.is_empty() {
+        format!(", \"{}\"", fmt_text)
+    } else {
+        format!(", \"{}\", {}", fmt_text, format_args)
+    }
+}
+
 fn get_async(item: &ItemFn) -> (String, String) {
     if item.sig.asyncness.is_some() {
         ("async".to_string(), ".await".to_string())
@@ -298,75 +672,592 @@ fn get_tuple_destructure(
     }
 }
 
-fn get_result_block(return_type: &AssertReturnType) -> (String, String) {
-    if matches!(
-        return_type,
-        AssertReturnType::ResultBool | AssertReturnType::ResultTuple(_)
-    ) {
-        ("if let Ok(result) = result {".to_string(), "}".to_string())
-    } else {
-        ("".to_string(), "".to_string())
+fn get_result_block(return_type: &AssertReturnType, require_ok: bool) -> (String, String) {
+    match return_type {
+        AssertReturnType::ResultBool | AssertReturnType::ResultTuple(_) if require_ok => (
+            r#"let result = match result { Ok(result) => result, Err(err) => panic!("assertion failed: expected Ok, got Err({:?})", err) };"#.to_string(),
+            "".to_string(),
+        ),
+        AssertReturnType::ResultBool | AssertReturnType::ResultTuple(_) => {
+            ("if let Ok(result) = result {".to_string(), "}".to_string())
+        }
+        _ => ("".to_string(), "".to_string()),
     }
 }
 
-fn get_assert_call(return_type: &AssertReturnType) -> String {
-    match return_type {
-        AssertReturnType::Bool | AssertReturnType::ResultBool => "assert!(result".to_string(),
-        AssertReturnType::Tuple(_) | AssertReturnType::ResultTuple(_) => {
-            "assert_eq!(result.0, result.1".to_string()
+/// Checks whether a bare flag (e.g. `require_ok`) is present among the `#[assert_fn(...)]` arguments.
+fn has_flag(args: &[NestedMeta], name: &str) -> bool {
+    args.iter().any(|item| match item {
+        NestedMeta::Meta(Meta::Path(path)) => path
+            .segments
+            .last()
+            .map(|seg| seg.ident == name)
+            .unwrap_or(false),
+        _ => false,
+    })
+}
+
+/// Builds a companion `assert_not_<fn_name>!` macro that asserts the opposite of the usual
+/// assertion, reusing the `get_assert_call` branch of the complement `negate_assert_type` picks
+/// (the `assert_ne_fn` branch for `#[assert_fn(negate)]`) and the same
+/// `build_assert_stmt` message composition as the primary macro, so it gets the chunk0-1 default
+/// "arg = value" diagnostic, `message(...)`, `context`, `payload` and `no_std` exactly like the
+/// macro it negates.
+#[allow(clippy::too_many_arguments)]
+fn get_negate_macro(
+    fn_name: &str,
+    params: &str,
+    values: &str,
+    async_block: &str,
+    dot_await: &str,
+    if_result_open: &str,
+    if_result_close: &str,
+    arg_bindings: &str,
+    assert_call: &str,
+    assert_stmt: &str,
+) -> String {
+    format!(
+        r#"
+        #[macro_export]
+        macro_rules! assert_not_{fn_name} {{
+            ({params_trimmed}$(,)?) => {{ {async_block} {{
+                {arg_bindings}
+                let result = {fn_name}({values}){dot_await};
+
+                {if_result_open}
+                {assert_stmt}
+                {if_result_close}
+
+                result
+            }}}};
+            ({params}$($arg:tt)+) => {{ {async_block} {{
+                {arg_bindings}
+                let result = {fn_name}({values}){dot_await};
+
+                {if_result_open}
+                {assert_call}, $($arg)*);
+                {if_result_close}
+
+                result
+            }}}};
+        }}
+        "#,
+        fn_name = fn_name,
+        params = params,
+        params_trimmed = params.trim_end_matches(|c| c == ','),
+        values = values.trim_end_matches(|c| c == ','),
+        async_block = async_block,
+        dot_await = dot_await,
+        if_result_open = if_result_open,
+        if_result_close = if_result_close,
+        arg_bindings = arg_bindings,
+        assert_call = assert_call,
+        assert_stmt = assert_stmt,
+    )
+}
+
+/// Builds a companion `assert_<fn_name>_panics!` macro, built on `test_helpers::catch_panic_message`,
+/// that asserts the underlying function panics, optionally matching the panic message against a
+/// substring. Only generated for non-`async` functions since `catch_unwind` can't wrap a future.
+fn get_panics_macro(fn_name: &str, params: &str, values: &str, arg_bindings: &str) -> String {
+    format!(
+        r#"
+        #[macro_export]
+        macro_rules! assert_{fn_name}_panics {{
+            ({params_trimmed}$(,)?) => {{
+                {arg_bindings}
+                let __panic_result = test_helpers::catch_panic_message(|| {{ {fn_name}({values}); }});
+                assert!(
+                    !matches!(__panic_result, test_helpers::PanicMessage::DidNotPanic),
+                    "assertion failed: expected `{fn_name}` to panic"
+                );
+            }};
+            ({params}$expected:expr) => {{
+                {arg_bindings}
+                let __panic_result = test_helpers::catch_panic_message(|| {{ {fn_name}({values}); }});
+                match __panic_result {{
+                    test_helpers::PanicMessage::Message(message) => assert!(
+                        message.contains($expected),
+                        "assertion failed: expected panic message to contain {{:?}}, got {{:?}}",
+                        $expected,
+                        message
+                    ),
+                    _ => panic!("assertion failed: expected `{fn_name}` to panic with a message"),
+                }}
+            }};
+        }}
+        "#,
+        fn_name = fn_name,
+        params = params,
+        params_trimmed = params.trim_end_matches(|c| c == ','),
+        values = values.trim_end_matches(|c| c == ','),
+        arg_bindings = arg_bindings,
+    )
+}
+
+/// Builds the `#[assert_fn(payload)]` failure path: instead of a formatted panic message, it
+/// panics with a typed `test_helpers::AssertFailure` payload via `std::panic::panic_any`, so a
+/// test can downcast and inspect `left`/`right`/`extras` programmatically. Only tuple-returning
+/// functions carry enough structure for this; anything else returns `None`. `fmt_text`/
+/// `format_args` are whatever `build_assert_stmt` computed for this assertion (the default
+/// diagnostic, a custom `message(...)`, or `context`'s extra lines); when non-empty they're
+/// rendered into `AssertFailure.message` instead of being silently dropped.
+fn get_payload_call(
+    return_type: &AssertReturnType,
+    assert_type: &AssertType,
+    fmt_text: &str,
+    format_args: &str,
+) -> Option<String> {
+    let tuple_size = match return_type {
+        AssertReturnType::Tuple(n) | AssertReturnType::ResultTuple(n) => *n,
+        _ => return None,
+    };
+
+    let condition = match assert_type {
+        AssertType::Eq => "result.0 == result.1",
+        AssertType::Ne => "result.0 != result.1",
+        AssertType::Lt => "result.0 < result.1",
+        AssertType::Le => "result.0 <= result.1",
+        AssertType::Gt => "result.0 > result.1",
+        AssertType::Ge => "result.0 >= result.1",
+    };
+
+    let extras = (2..tuple_size)
+        .map(|n| format!("format!(\"{{:?}}\", result.{})", n))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let message_field = if fmt_text.is_empty() {
+        "None".to_string()
+    } else if format_args.is_empty() {
+        format!("Some(format!(\"{}\"))", fmt_text)
+    } else {
+        format!("Some(format!(\"{}\", {}))", fmt_text, format_args)
+    };
+
+    Some(format!(
+        r#"if !({condition}) {{
+            std::panic::panic_any(test_helpers::AssertFailure {{
+                left: format!("{{:?}}", result.0),
+                right: format!("{{:?}}", result.1),
+                extras: vec![{extras}],
+                message: {message_field},
+            }});
+        }}"#,
+        condition = condition,
+        extras = extras,
+        message_field = message_field,
+    ))
+}
+
+/// Checks for `no_std` / `no_std(capacity)` among the `#[assert_fn(...)]` arguments: an
+/// `#[assert_fn]` function can opt a specific assertion into formatting its message into a
+/// fixed-capacity buffer (default 256 bytes) instead of relying on `std::String`. The generated
+/// code only actually takes that buffer-based path when the consuming crate's own `no_std`
+/// Cargo feature is enabled (see `get_no_std_assert_stmt`); this just parses the requested
+/// capacity out of the attribute.
+fn get_no_std_capacity(args: &[NestedMeta]) -> Option<u32> {
+    const DEFAULT_CAPACITY: u32 = 256;
+
+    args.iter().find_map(|item| match item {
+        NestedMeta::Meta(Meta::Path(path))
+            if path.segments.last().map(|seg| seg.ident == "no_std") == Some(true) =>
+        {
+            Some(DEFAULT_CAPACITY)
+        }
+        NestedMeta::Meta(Meta::List(list))
+            if list.path.segments.last().map(|seg| seg.ident == "no_std") == Some(true) =>
+        {
+            let capacity = list.nested.iter().find_map(|nested| match nested {
+                NestedMeta::Lit(Lit::Int(capacity)) => capacity.base10_parse::<u32>().ok(),
+                _ => None,
+            });
+            Some(capacity.unwrap_or(DEFAULT_CAPACITY))
         }
+        _ => None,
+    })
+}
+
+/// Renders the failure message into a stack-allocated `arrayvec::ArrayString<CAPACITY>` via
+/// `core::fmt::Write` rather than `format!`'s `String`, then passes the resulting `&str` on to
+/// the usual `assert!`/`assert_eq!` call. Overflowing the buffer truncates the message instead of
+/// panicking, since losing some diagnostic text is preferable to the assertion itself failing.
+///
+/// The `arrayvec` path only compiles when the *consuming* crate (the one using
+/// `#[assert_fn(no_std(...))]`) enables its own `no_std` Cargo feature and depends on `arrayvec` -
+/// this generated code becomes part of that crate, so it's the only place such a feature can
+/// live. With that feature off, which is the default, this falls back to the unchanged `std`
+/// path, so enabling `no_std(...)` on an assertion is a no-op until the consumer opts in.
+fn get_no_std_assert_stmt(
+    assert_call: &str,
+    fmt_text: &str,
+    format_args: &str,
+    capacity: u32,
+) -> String {
+    if fmt_text.is_empty() {
+        return format!("{});", assert_call);
     }
+
+    let std_message = render_message_suffix(fmt_text, format_args);
+
+    format!(
+        r#"{{
+            #[cfg(feature = "no_std")]
+            {{
+                let mut __msg_buf: arrayvec::ArrayString<{capacity}> = arrayvec::ArrayString::new();
+                let _ = core::fmt::Write::write_fmt(&mut __msg_buf, format_args!("{fmt_text}", {format_args}));
+                {assert_call}, "{{}}", __msg_buf.as_str());
+            }}
+            #[cfg(not(feature = "no_std"))]
+            {{
+                {assert_call}{std_message});
+            }}
+        }}"#,
+        capacity = capacity,
+        fmt_text = fmt_text,
+        format_args = format_args,
+        assert_call = assert_call,
+        std_message = std_message,
+    )
 }
 
-#[derive(Clone)]
-struct AssertMessage {
-    message: String,
-    args: Vec<String>,
+/// Appends a labeled `Debug` line for every tuple element beyond `left`/`right` to the failure
+/// message, so `#[assert_fn(context)]` surfaces the "other useful values" a function computed
+/// without the caller having to list them all in `message(...)`. Each line is labeled with the
+/// name it was destructured to via `message(...)`, falling back to `result.N` otherwise.
+fn append_context_block(
+    return_type: &AssertReturnType,
+    assert_message: &Option<AssertMessage>,
+    fmt_text: &mut String,
+    format_args: &mut String,
+) {
+    let tuple_size = match return_type {
+        AssertReturnType::Tuple(n) | AssertReturnType::ResultTuple(n) if *n > 2 => *n,
+        _ => return,
+    };
+
+    let idents = assert_message
+        .as_ref()
+        .map(|msg| msg.args.clone())
+        .unwrap_or_default();
+
+    let fields: Vec<(String, u8)> = (2..tuple_size)
+        .map(|n| match idents.get(n as usize) {
+            Some(ident) if ident != "_" => (ident.clone(), n),
+            _ => (format!("result.{}", n), n),
+        })
+        .collect();
+
+    if fields.is_empty() {
+        return;
+    }
+
+    // Named args, since `message(...)`'s own args are named and positional ones can't follow them.
+    for (label, n) in &fields {
+        fmt_text.push_str(&format!("\n    {} = {{__context_{}:?}}", label, n));
+    }
+
+    let extra_args = fields
+        .iter()
+        .map(|(_, n)| format!("__context_{n} = result.{n}", n = n))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if format_args.is_empty() {
+        *format_args = extra_args;
+    } else {
+        format_args.push_str(", ");
+        format_args.push_str(&extra_args);
+    }
+}
+
+/// Builds the full assertion statement (e.g. `assert_eq!(result.0, result.1, "fmt", args);`) for
+/// a given `assert_type`, composing the default/custom message, `context`, `payload` and
+/// `no_std(...)` options exactly as `create_assert_macro` does for the primary macro. Shared so
+/// the `negate` companion gets the same diagnostics instead of a stripped-down panic.
+fn build_assert_stmt(
+    args: &[NestedMeta],
+    assert_type: &AssertType,
+    return_type: &AssertReturnType,
+    assert_message: &Option<AssertMessage>,
+    param_idents: &[Option<String>],
+) -> String {
+    let assert_call = get_assert_call(return_type, assert_type);
+    let (mut fmt_text, mut format_args) = match assert_message {
+        Some(msg) => (msg.fmt_text.clone(), msg.format_args.clone()),
+        None => get_default_message_parts(param_idents),
+    };
+    if has_flag(args, "context") {
+        append_context_block(return_type, assert_message, &mut fmt_text, &mut format_args);
+    }
+    let message = render_message_suffix(&fmt_text, &format_args);
+
+    if has_flag(args, "payload") {
+        get_payload_call(return_type, assert_type, &fmt_text, &format_args).unwrap_or_else(|| {
+            panic!("#[assert_fn(payload)] requires a function returning a tuple")
+        })
+    } else if let Some(capacity) = get_no_std_capacity(args) {
+        get_no_std_assert_stmt(&assert_call, &fmt_text, &format_args, capacity)
+    } else {
+        format!("{}{});", assert_call, message)
+    }
+}
+
+fn get_assert_call(return_type: &AssertReturnType, assert_type: &AssertType) -> String {
+    let is_tuple = matches!(
+        return_type,
+        AssertReturnType::Tuple(_) | AssertReturnType::ResultTuple(_)
+    );
+
+    match assert_type {
+        AssertType::Eq if is_tuple => "assert_eq!(result.0, result.1".to_string(),
+        AssertType::Eq => "assert!(result".to_string(),
+        AssertType::Ne if is_tuple => "assert_ne!(result.0, result.1".to_string(),
+        AssertType::Ne => "assert!(!result".to_string(),
+        AssertType::Lt if is_tuple => "assert!(result.0 < result.1".to_string(),
+        AssertType::Le if is_tuple => "assert!(result.0 <= result.1".to_string(),
+        AssertType::Gt if is_tuple => "assert!(result.0 > result.1".to_string(),
+        AssertType::Ge if is_tuple => "assert!(result.0 >= result.1".to_string(),
+        _ => panic!("Ordering assertions require a function returning a tuple"),
+    }
 }
 
-fn get_message(args: &[NestedMeta]) -> Option<AssertMessage> {
+/// Generates one `#[test]` per `case(...)` attribute argument, each invoking the macro this
+/// function produces so a single `#[assert_fn]` function defines a whole table-driven suite.
+fn get_test_cases(args: &[NestedMeta], fn_name: &str, is_async: bool) -> String {
     args.iter()
         .filter_map(|item| match item {
             NestedMeta::Meta(Meta::List(list)) => Some(list),
             _ => None,
         })
-        .filter_map(|list| {
+        .filter(|list| {
             list.path
                 .segments
                 .last()
-                .filter(|seg| seg.ident == "message")
-                .map(|_| list.nested.clone())
+                .map(|seg| seg.ident == "case")
+                .unwrap_or(false)
         })
-        .find_map(|params| {
-            let mut iter = params.into_iter();
-            match iter.next() {
-                // The first item in our param list should be the message string literal
-                Some(NestedMeta::Lit(Lit::Str(str))) => Some(str.value()),
-                _ => None,
+        .enumerate()
+        .map(|(n, list)| get_test_case(list, fn_name, n, is_async))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn get_test_case(list: &syn::MetaList, fn_name: &str, n: usize, is_async: bool) -> String {
+    let mut call_args = Vec::new();
+    let mut should_panic = None;
+
+    for nested in &list.nested {
+        match nested {
+            NestedMeta::Lit(lit) => call_args.push(lit_to_source(lit)),
+            NestedMeta::Meta(Meta::Path(path))
+                if path.segments.last().map(|seg| seg.ident == "panics") == Some(true) =>
+            {
+                should_panic.get_or_insert(None);
+            }
+            NestedMeta::Meta(Meta::NameValue(name_value))
+                if name_value
+                    .path
+                    .segments
+                    .last()
+                    .map(|seg| seg.ident == "message")
+                    == Some(true) =>
+            {
+                if let Lit::Str(message) = &name_value.lit {
+                    should_panic = Some(Some(message.value()));
+                }
             }
-            .map(|message| {
-                // And the rest are message args
-                let args = iter
-                    .filter_map(|nested_meta| match nested_meta {
-                        NestedMeta::Meta(Meta::Path(path)) => path.segments.last().cloned(),
-                        _ => None,
-                    })
-                    .map(|seg| seg.ident.to_string())
-                    .collect::<Vec<_>>();
-
-                let message = if args.is_empty() {
-                    format!(", \"{}\"", message)
-                } else {
-                    let used_args = args
-                        .iter()
-                        .filter(|arg| *arg != "_")
-                        .map(|arg| format!("{}={}", arg, arg))
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    format!(", \"{}\", {}", message, used_args)
-                };
-
-                AssertMessage { message, args }
+            _ => {}
+        }
+    }
+
+    let should_panic_attr = match should_panic {
+        Some(Some(message)) => format!("#[should_panic(expected = {:?})]", message),
+        Some(None) => "#[should_panic]".to_string(),
+        None => "".to_string(),
+    };
+    let call_args = call_args.join(", ");
+
+    if is_async {
+        format!(
+            "#[tokio::test] {should_panic_attr} async fn {fn_name}_case_{n}() {{ assert_{fn_name}!({call_args}).await; }}",
+            should_panic_attr = should_panic_attr,
+            fn_name = fn_name,
+            n = n,
+            call_args = call_args
+        )
+    } else {
+        format!(
+            "#[test] {should_panic_attr} fn {fn_name}_case_{n}() {{ assert_{fn_name}!({call_args}); }}",
+            should_panic_attr = should_panic_attr,
+            fn_name = fn_name,
+            n = n,
+            call_args = call_args
+        )
+    }
+}
+
+/// Renders a literal attribute argument (e.g. `10`, `"boom"`, `true`) back into the Rust source
+/// text needed to pass it on as a macro call argument.
+fn lit_to_source(lit: &Lit) -> String {
+    match lit {
+        Lit::Str(value) => format!("{:?}", value.value()),
+        Lit::Int(value) => value.base10_digits().to_string(),
+        Lit::Float(value) => value.base10_digits().to_string(),
+        Lit::Bool(value) => value.value.to_string(),
+        Lit::Char(value) => format!("{:?}", value.value()),
+        _ => panic!("Unsupported literal in case(...)"),
+    }
+}
+
+#[derive(Clone)]
+struct AssertMessage {
+    message: String,
+    args: Vec<String>,
+    fmt_text: String,
+    format_args: String,
+}
+
+/// Finds the `message(...)` attribute argument, if any. On success returns the parsed
+/// [`AssertMessage`]; on a validation failure (an unbalanced or unresolvable `{...}` placeholder)
+/// returns a `compile_error!` token stream spanned to the offending message string literal,
+/// rather than panicking at macro-expansion time with a generic "proc macro panicked" diagnostic.
+fn get_message(args: &[NestedMeta]) -> Result<Option<AssertMessage>, proc_macro2::TokenStream> {
+    for list in args.iter().filter_map(|item| match item {
+        NestedMeta::Meta(Meta::List(list)) => Some(list),
+        _ => None,
+    }) {
+        let is_message = list
+            .path
+            .segments
+            .last()
+            .map(|seg| seg.ident == "message")
+            .unwrap_or(false);
+        if !is_message {
+            continue;
+        }
+
+        let mut iter = list.nested.iter();
+        // The first item in our param list should be the message string literal
+        let message_lit = match iter.next() {
+            Some(NestedMeta::Lit(Lit::Str(str))) => str,
+            _ => continue,
+        };
+        let message = message_lit.value();
+
+        // And the rest are message args
+        let args = iter
+            .filter_map(|nested_meta| match nested_meta {
+                NestedMeta::Meta(Meta::Path(path)) => path.segments.last().cloned(),
+                _ => None,
             })
-        })
+            .map(|seg| seg.ident.to_string())
+            .collect::<Vec<_>>();
+
+        if let Err(err) = validate_message_placeholders(&message, &args) {
+            let err_text = format!("Invalid message(\"{}\", ...): {}", message, err);
+            return Err(syn::Error::new_spanned(message_lit, err_text).to_compile_error());
+        }
+
+        let format_args = args
+            .iter()
+            .filter(|arg| *arg != "_")
+            .map(|arg| format!("{}={}", arg, arg))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let rendered_message = render_message_suffix(&message, &format_args);
+
+        return Ok(Some(AssertMessage {
+            message: rendered_message,
+            args,
+            fmt_text: message,
+            format_args,
+        }));
+    }
+
+    Ok(None)
+}
+
+/// Walks a `message(...)` format string's `{...}` placeholders, checking braces are balanced and
+/// every named placeholder resolves to one of the destructured `args`, so a typo'd or missing
+/// placeholder is a compile error instead of a panic when the assertion eventually fires.
+fn validate_message_placeholders(message: &str, args: &[String]) -> Result<(), String> {
+    let available = args
+        .iter()
+        .filter(|arg| arg.as_str() != "_")
+        .collect::<Vec<_>>();
+
+    let mut chars = message.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' => {
+                if chars.peek().map(|(_, c2)| *c2) == Some('{') {
+                    chars.next();
+                    continue;
+                }
+
+                let mut placeholder = String::new();
+                let mut closed = false;
+                for (_, c2) in chars.by_ref() {
+                    if c2 == '}' {
+                        closed = true;
+                        break;
+                    }
+                    placeholder.push(c2);
+                }
+
+                if !closed {
+                    return Err(format!("unclosed `{{` at byte offset {}", i));
+                }
+
+                let ident = placeholder.split(':').next().unwrap_or("").trim();
+
+                if ident.is_empty() || ident.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(format!(
+                        "positional placeholder `{{{}}}` isn't supported, use a named placeholder instead",
+                        placeholder
+                    ));
+                }
+
+                if !is_rust_ident(ident) {
+                    // Not a bare identifier (e.g. an inline expression) - leave it for rustc to validate.
+                    continue;
+                }
+
+                if !available.iter().any(|arg| arg.as_str() == ident) {
+                    return Err(format!(
+                        "placeholder `{{{}}}` doesn't match any of the destructured args ({})",
+                        ident,
+                        if available.is_empty() {
+                            "none".to_string()
+                        } else {
+                            available
+                                .iter()
+                                .map(|arg| arg.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        }
+                    ));
+                }
+            }
+            '}' => {
+                if chars.peek().map(|(_, c2)| *c2) == Some('}') {
+                    chars.next();
+                    continue;
+                }
+                return Err(format!("unmatched `}}` at byte offset {}", i));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn is_rust_ident(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_alphanumeric() || c == '_')
 }
@@ -1,4 +1,4 @@
-use assert_fn::assert_fn;
+use assert_fn::{assert_fn, assert_lt_fn};
 use test_helpers::{catch_panic_message, PanicMessage};
 
 #[assert_fn(message("{num} wasn't quite right", num))]
@@ -26,4 +26,81 @@ fn it_can_copy() -> Result<(String, String), ()> {
 #[test]
 fn it_can_return_a_result_of_something_that_doesnt_implement_copy() {
     assert_it_can_copy!("test");
-}
\ No newline at end of file
+}
+
+#[assert_fn]
+fn is_twenty(num: usize) -> bool {
+    num == 20
+}
+
+#[test]
+fn it_accepts_trailing_format_args_at_the_call_site() {
+    let result = catch_panic_message(|| assert_is_twenty!(9, "custom {} context", "runtime"));
+    assert_eq!(
+        result,
+        PanicMessage::Message("custom runtime context".to_string())
+    )
+}
+
+#[assert_fn]
+fn is_even(num: usize) -> bool {
+    if num == 13 {
+        panic!("unlucky number");
+    }
+    num % 2 == 0
+}
+
+#[test]
+fn it_asserts_the_function_panics() {
+    assert_is_even_panics!(13);
+}
+
+#[test]
+fn it_asserts_the_function_panics_with_a_message_containing() {
+    assert_is_even_panics!(13, "unlucky");
+}
+
+#[test]
+#[should_panic(expected = "expected `is_even` to panic")]
+fn it_fails_when_the_function_does_not_panic() {
+    assert_is_even_panics!(4);
+}
+
+#[test]
+#[should_panic(expected = "expected panic message to contain")]
+fn it_fails_when_the_panic_message_does_not_contain_the_expected_substring() {
+    assert_is_even_panics!(13, "wrong substring");
+}
+
+#[assert_fn(case(10), case(9, panics), case(8, message = "... (num = 8)"))]
+fn is_ten_with_cases(num: usize) -> bool {
+    num == 10
+}
+
+#[assert_lt_fn(negate)]
+fn is_before(a: usize, b: usize) -> (usize, usize) {
+    (a, b)
+}
+
+#[test]
+fn it_negates_an_ordering_assertion_to_its_true_complement() {
+    // 5 is not before 5, but it also isn't "not equal" to 5 - assert_not_is_before! must assert
+    // `>=`, not `!=`, or this would wrongly panic and the (4, 5) case would wrongly pass.
+    assert_not_is_before!(5, 5);
+    assert_not_is_before!(6, 5);
+    assert!(std::panic::catch_unwind(|| assert_not_is_before!(4, 5)).is_err());
+}
+
+#[assert_fn(no_std(64))]
+fn is_thirty(num: usize) -> bool {
+    num == 30
+}
+
+#[test]
+fn it_still_asserts_correctly_with_no_std_capacity_set_and_the_feature_off() {
+    // The `arrayvec` path behind `no_std(...)` only compiles in when this crate enables its own
+    // `no_std` Cargo feature - with the feature off (the default here), the assertion falls back
+    // to the ordinary `std` formatting unchanged.
+    assert_is_thirty!(30);
+    assert!(std::panic::catch_unwind(|| assert_is_thirty!(9)).is_err());
+}
@@ -1,18 +1,32 @@
 use std::panic::{catch_unwind, UnwindSafe};
 
+/// The structured payload `#[assert_fn(payload)]` panics with via `std::panic::panic_any`,
+/// carrying the compared values plus any further tuple elements the function returned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssertFailure {
+    pub left: String,
+    pub right: String,
+    pub extras: Vec<String>,
+    pub message: Option<String>,
+}
+
 #[derive(Debug, PartialEq)]
 pub enum PanicMessage {
     Message(String),
+    Payload(AssertFailure),
     CouldNotGetMessage,
     DidNotPanic,
 }
 
 pub fn catch_panic_message<F: FnOnce() -> R + UnwindSafe, R>(f: F) -> PanicMessage {
     match catch_unwind(f) {
-        Err(panic) => match (panic.downcast_ref::<String>(), panic.downcast_ref::<&str>()) {
-            (Some(panic_message), _) => PanicMessage::Message(panic_message.to_string()),
-            (_, Some(panic_message)) => PanicMessage::Message(panic_message.to_string()),
-            _ => PanicMessage::CouldNotGetMessage,
+        Err(panic) => match panic.downcast::<AssertFailure>() {
+            Ok(failure) => PanicMessage::Payload(*failure),
+            Err(panic) => match (panic.downcast_ref::<String>(), panic.downcast_ref::<&str>()) {
+                (Some(panic_message), _) => PanicMessage::Message(panic_message.to_string()),
+                (_, Some(panic_message)) => PanicMessage::Message(panic_message.to_string()),
+                _ => PanicMessage::CouldNotGetMessage,
+            },
         },
         _ => PanicMessage::DidNotPanic,
     }